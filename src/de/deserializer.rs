@@ -0,0 +1,496 @@
+use core::marker::PhantomData;
+
+use serde::de::{
+    self, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess,
+    Visitor,
+};
+
+use super::reader::Reader;
+use crate::{Error, Result};
+
+/// Deserialization implementation for BCS, reading through a pluggable
+/// [`Reader`] so the same decoder works over a borrowed slice (zero-copy) or a
+/// streaming [`std::io::Read`].
+pub struct Deserializer<'de, R> {
+    reader: R,
+    max_remaining_depth: usize,
+    phantom: PhantomData<&'de ()>,
+}
+
+impl<'de, R> Deserializer<'de, R>
+where
+    R: Reader<'de>,
+{
+    /// Creates a new `Deserializer` reading from `reader`.
+    pub fn new(reader: R, max_remaining_depth: usize) -> Self {
+        Self {
+            reader,
+            max_remaining_depth,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Consume the deserializer and return the underlying reader, e.g. to run
+    /// [`SliceReader::finish`](super::reader::SliceReader::finish).
+    pub fn into_reader(self) -> R {
+        self.reader
+    }
+
+    fn enter_named_container(&mut self, name: &'static str) -> Result<()> {
+        if self.max_remaining_depth == 0 {
+            return Err(Error::ExceededContainerDepthLimit(name));
+        }
+        self.max_remaining_depth -= 1;
+        Ok(())
+    }
+
+    fn leave_named_container(&mut self) {
+        self.max_remaining_depth += 1;
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut buf = [0u8; N];
+        buf.copy_from_slice(self.reader.read(N)?);
+        Ok(buf)
+    }
+
+    /// Decode a ULEB128-encoded `u32`, rejecting non-minimal encodings and
+    /// values that do not fit in a `u32`, mirroring the serializer's output.
+    fn parse_u32_from_uleb128(&mut self) -> Result<u32> {
+        let mut value: u64 = 0;
+        for shift in (0..32).step_by(7) {
+            let byte = self.reader.read_byte()?;
+            let digit = (byte & 0x7f) as u64;
+            value |= digit << shift;
+            if byte & 0x80 == 0 {
+                if value > u32::MAX as u64 {
+                    return Err(Error::IntegerOverflowDuringUleb128Decoding);
+                }
+                // A trailing zero digit (other than the very first byte) means
+                // the encoding was not minimal.
+                if digit == 0 && shift != 0 {
+                    return Err(Error::NonCanonicalUleb128Encoding);
+                }
+                return Ok(value as u32);
+            }
+        }
+        Err(Error::IntegerOverflowDuringUleb128Decoding)
+    }
+
+    /// Decode a sequence length, enforcing `MAX_SEQUENCE_LENGTH`.
+    fn parse_length(&mut self) -> Result<usize> {
+        let len = self.parse_u32_from_uleb128()? as usize;
+        if len > crate::MAX_SEQUENCE_LENGTH {
+            return Err(Error::ExceededMaxLen(len));
+        }
+        Ok(len)
+    }
+}
+
+impl<'a, 'de, R> de::Deserializer<'de> for &'a mut Deserializer<'de, R>
+where
+    R: Reader<'de>,
+{
+    type Error = Error;
+
+    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        // BCS is not a self-describing format.
+        Err(Error::NotSupported("deserialize_any"))
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self.reader.read_byte()? {
+            0 => visitor.visit_bool(false),
+            1 => visitor.visit_bool(true),
+            _ => Err(Error::ExpectedBoolean),
+        }
+    }
+
+    fn deserialize_i8<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i8(self.reader.read_byte()? as i8)
+    }
+
+    fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i16(i16::from_le_bytes(self.read_array()?))
+    }
+
+    fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i32(i32::from_le_bytes(self.read_array()?))
+    }
+
+    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i64(i64::from_le_bytes(self.read_array()?))
+    }
+
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i128(i128::from_le_bytes(self.read_array()?))
+    }
+
+    fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u8(self.reader.read_byte()?)
+    }
+
+    fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u16(u16::from_le_bytes(self.read_array()?))
+    }
+
+    fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u32(u32::from_le_bytes(self.read_array()?))
+    }
+
+    fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u64(u64::from_le_bytes(self.read_array()?))
+    }
+
+    fn deserialize_u128<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u128(u128::from_le_bytes(self.read_array()?))
+    }
+
+    fn deserialize_f32<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::NotSupported("deserialize_f32"))
+    }
+
+    fn deserialize_f64<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::NotSupported("deserialize_f64"))
+    }
+
+    fn deserialize_char<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::NotSupported("deserialize_char"))
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let len = self.parse_length()?;
+        self.reader.forward_read_str(len, visitor)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let len = self.parse_length()?;
+        self.reader.forward_read_bytes(len, visitor)
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self.reader.read_byte()? {
+            0 => visitor.visit_none(),
+            1 => visitor.visit_some(self),
+            _ => Err(Error::ExpectedOption),
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.enter_named_container(name)?;
+        let result = visitor.visit_unit();
+        self.leave_named_container();
+        result
+    }
+
+    fn deserialize_newtype_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.enter_named_container(name)?;
+        let result = visitor.visit_newtype_struct(&mut *self);
+        self.leave_named_container();
+        result
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let len = self.parse_length()?;
+        visitor.visit_seq(SeqDeserializer::new(&mut *self, len))
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(SeqDeserializer::new(&mut *self, len))
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.enter_named_container(name)?;
+        let result = visitor.visit_seq(SeqDeserializer::new(&mut *self, len));
+        self.leave_named_container();
+        result
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let len = self.parse_length()?;
+        visitor.visit_map(MapDeserializer::new(&mut *self, len))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.enter_named_container(name)?;
+        let result = visitor.visit_seq(SeqDeserializer::new(&mut *self, fields.len()));
+        self.leave_named_container();
+        result
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.enter_named_container(name)?;
+        let result = visitor.visit_enum(EnumDeserializer::new(&mut *self));
+        self.leave_named_container();
+        result
+    }
+
+    fn deserialize_identifier<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::NotSupported("deserialize_identifier"))
+    }
+
+    fn deserialize_ignored_any<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::NotSupported("deserialize_ignored_any"))
+    }
+
+    // BCS is not a human readable format
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+struct SeqDeserializer<'a, 'de, R> {
+    de: &'a mut Deserializer<'de, R>,
+    remaining: usize,
+}
+
+impl<'a, 'de, R> SeqDeserializer<'a, 'de, R> {
+    fn new(de: &'a mut Deserializer<'de, R>, len: usize) -> Self {
+        Self { de, remaining: len }
+    }
+}
+
+impl<'a, 'de, R> SeqAccess<'de> for SeqDeserializer<'a, 'de, R>
+where
+    R: Reader<'de>,
+{
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+struct MapDeserializer<'a, 'de, R> {
+    de: &'a mut Deserializer<'de, R>,
+    remaining: usize,
+}
+
+impl<'a, 'de, R> MapDeserializer<'a, 'de, R> {
+    fn new(de: &'a mut Deserializer<'de, R>, len: usize) -> Self {
+        Self { de, remaining: len }
+    }
+}
+
+impl<'a, 'de, R> MapAccess<'de> for MapDeserializer<'a, 'de, R>
+where
+    R: Reader<'de>,
+{
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<T>(&mut self, seed: T) -> Result<T::Value>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+struct EnumDeserializer<'a, 'de, R> {
+    de: &'a mut Deserializer<'de, R>,
+}
+
+impl<'a, 'de, R> EnumDeserializer<'a, 'de, R> {
+    fn new(de: &'a mut Deserializer<'de, R>) -> Self {
+        Self { de }
+    }
+}
+
+impl<'a, 'de, R> EnumAccess<'de> for EnumDeserializer<'a, 'de, R>
+where
+    R: Reader<'de>,
+{
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant)>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let index = self.de.parse_u32_from_uleb128()?;
+        let value = seed.deserialize(index.into_deserializer())?;
+        Ok((value, self))
+    }
+}
+
+impl<'a, 'de, R> VariantAccess<'de> for EnumDeserializer<'a, 'de, R>
+where
+    R: Reader<'de>,
+{
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(SeqDeserializer::new(&mut *self.de, len))
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(SeqDeserializer::new(&mut *self.de, fields.len()))
+    }
+}
@@ -0,0 +1,103 @@
+use serde::Deserialize;
+
+use crate::{Error, Result, MAX_CONTAINER_DEPTH};
+
+pub mod deserializer;
+pub mod reader;
+
+pub use deserializer::Deserializer;
+pub use reader::{BorrowReader, Reader, SliceReader};
+#[cfg(feature = "std")]
+pub use reader::IoReader;
+
+/// Deserialize a value from a borrowed byte slice.
+///
+/// The slice is decoded through a [`reader::SliceReader`], so
+/// `Deserialize<'a>` implementations can borrow `&'a str`/`&'a [u8]` directly
+/// out of `bytes` without copying. BCS requires a canonical encoding, so any
+/// trailing bytes left after `T` has been read are reported as
+/// [`Error::RemainingInput`].
+pub fn from_bytes<'a, T>(bytes: &'a [u8]) -> Result<T>
+where
+    T: Deserialize<'a>,
+{
+    from_bytes_with_limit(bytes, MAX_CONTAINER_DEPTH)
+}
+
+/// Same as [`from_bytes`] but use `limit` as the max container depth instead of
+/// `MAX_CONTAINER_DEPTH`. Note that `limit` must not exceed
+/// `MAX_CONTAINER_DEPTH`.
+pub fn from_bytes_with_limit<'a, T>(bytes: &'a [u8], limit: usize) -> Result<T>
+where
+    T: Deserialize<'a>,
+{
+    if limit > MAX_CONTAINER_DEPTH {
+        return Err(Error::NotSupported("limit exceeds the max allowed depth"));
+    }
+
+    let mut deserializer = Deserializer::new(SliceReader::new(bytes), limit);
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.into_reader().finish()?;
+    Ok(value)
+}
+
+/// Deserialize a value by streaming bytes out of an [`io::Read`], decoding
+/// directly off e.g. a socket without buffering the whole message first.
+///
+/// Because the input is not retained, only owned values (`DeserializeOwned`)
+/// can be produced; I/O failures surface as [`Error::Io`].
+///
+/// [`io::Read`]: std::io::Read
+#[cfg(feature = "std")]
+pub fn from_reader<R, T>(reader: R) -> Result<T>
+where
+    R: std::io::Read,
+    T: serde::de::DeserializeOwned,
+{
+    let mut deserializer = Deserializer::new(reader::IoReader::new(reader), MAX_CONTAINER_DEPTH);
+    T::deserialize(&mut deserializer)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{from_bytes, from_bytes_with_limit};
+    use crate::ser::to_bytes;
+    use crate::Error;
+
+    #[test]
+    fn round_trips_through_slice_reader() {
+        let value: (u32, bool, i16) = (0x1234_5678, true, -3);
+        let bytes = to_bytes(&value).unwrap();
+        let decoded: (u32, bool, i16) = from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn borrows_str_zero_copy() {
+        let bytes = to_bytes("hello").unwrap();
+        // `&str` only deserializes if the reader hands out a borrowed slice.
+        let decoded: &str = from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, "hello");
+    }
+
+    #[test]
+    fn rejects_trailing_input() {
+        let mut bytes = to_bytes(&7u32).unwrap();
+        bytes.push(0);
+        let result: Result<u32, _> = from_bytes(&bytes);
+        assert!(matches!(result, Err(Error::RemainingInput)));
+    }
+
+    #[test]
+    fn reports_short_input_as_eof() {
+        let result: Result<u32, _> = from_bytes(&[0u8, 1]);
+        assert!(matches!(result, Err(Error::Eof)));
+    }
+
+    #[test]
+    fn limit_must_not_exceed_max_depth() {
+        let bytes = to_bytes(&0u8).unwrap();
+        let result: Result<u8, _> = from_bytes_with_limit(&bytes, usize::MAX);
+        assert!(matches!(result, Err(Error::NotSupported(_))));
+    }
+}
@@ -0,0 +1,201 @@
+//! Input abstraction for BCS deserialization, mirroring the pluggable
+//! [`Flavor`](crate::ser::flavors::Flavor) trait on the serialization side.
+
+use serde::de::Visitor;
+
+use crate::{Error, Result};
+
+/// A source of bytes consumed during deserialization.
+///
+/// This plays the same role for decoding that [`Flavor`] plays for encoding:
+/// it hides whether the bytes come from an in-memory slice or are streamed off
+/// a socket. [`read`](Reader::read) hands back the next `len` bytes,
+/// [`read_byte`](Reader::read_byte) is the common single-byte case, and
+/// [`remaining`](Reader::remaining) reports how many bytes are left when that
+/// is known.
+///
+/// The [`forward_read_bytes`](Reader::forward_read_bytes) /
+/// [`forward_read_str`](Reader::forward_read_str) hooks let a reader decide
+/// whether it can hand the visitor a slice borrowed for the full `'de`
+/// lifetime (zero-copy, see [`SliceReader`]) or must pass a transient copy
+/// (streaming, see [`IoReader`]).
+///
+/// [`Flavor`]: crate::ser::flavors::Flavor
+pub trait Reader<'de> {
+    /// Consume and return the next `len` bytes, or [`Error::Eof`] if fewer than
+    /// `len` bytes remain.
+    fn read(&mut self, len: usize) -> Result<&[u8]>;
+
+    /// Consume and return the next byte, or [`Error::Eof`] at end of input.
+    fn read_byte(&mut self) -> Result<u8> {
+        Ok(self.read(1)?[0])
+    }
+
+    /// Number of bytes still available, or `None` when the length is unknown
+    /// (e.g. a streaming reader).
+    fn remaining(&self) -> Option<usize>;
+
+    /// Read `len` bytes and hand them to `visitor`. The default implementation
+    /// passes a transient slice via [`Visitor::visit_bytes`]; readers that own
+    /// the input for `'de` override this to pass a borrowed slice.
+    fn forward_read_bytes<V>(&mut self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_bytes(self.read(len)?)
+    }
+
+    /// Read `len` bytes, validate them as UTF-8, and hand them to `visitor`.
+    /// The default implementation passes a transient slice via
+    /// [`Visitor::visit_str`]; borrowing readers override this.
+    fn forward_read_str<V>(&mut self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let s = core::str::from_utf8(self.read(len)?).map_err(|_| Error::Utf8)?;
+        visitor.visit_str(s)
+    }
+}
+
+/// A [`Reader`] that can hand out slices borrowed for the full lifetime of the
+/// input, enabling zero-copy `Deserialize<'de>` implementations to keep
+/// `&'de str` / `&'de [u8]` references into the original buffer.
+pub trait BorrowReader<'de>: Reader<'de> {
+    /// Consume and return the next `len` bytes borrowed from the underlying
+    /// input, or [`Error::Eof`] if fewer than `len` bytes remain.
+    fn read_borrowed(&mut self, len: usize) -> Result<&'de [u8]>;
+}
+
+/// A [`Reader`] over a borrowed byte slice.
+///
+/// Reads are zero-copy: each read returns a sub-slice of the original input
+/// rather than copying, and `&'de str`/`&'de [u8]` are handed to visitors
+/// borrowed. Call [`finish`](SliceReader::finish) once decoding is complete to
+/// enforce the canonical requirement that no trailing bytes are left over.
+pub struct SliceReader<'de> {
+    input: &'de [u8],
+}
+
+impl<'de> SliceReader<'de> {
+    /// Create a reader over `input`.
+    pub fn new(input: &'de [u8]) -> Self {
+        Self { input }
+    }
+
+    /// Assert that the entire input was consumed, returning
+    /// [`Error::RemainingInput`] otherwise. BCS requires a canonical encoding,
+    /// so trailing bytes are an error.
+    pub fn finish(self) -> Result<()> {
+        if self.input.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::RemainingInput)
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'de [u8]> {
+        if self.input.len() < len {
+            return Err(Error::Eof);
+        }
+        let (head, tail) = self.input.split_at(len);
+        self.input = tail;
+        Ok(head)
+    }
+}
+
+impl<'de> Reader<'de> for SliceReader<'de> {
+    fn read(&mut self, len: usize) -> Result<&[u8]> {
+        self.take(len)
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        Some(self.input.len())
+    }
+
+    fn forward_read_bytes<V>(&mut self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_borrowed_bytes(self.take(len)?)
+    }
+
+    fn forward_read_str<V>(&mut self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let s = core::str::from_utf8(self.take(len)?).map_err(|_| Error::Utf8)?;
+        visitor.visit_borrowed_str(s)
+    }
+}
+
+impl<'de> BorrowReader<'de> for SliceReader<'de> {
+    fn read_borrowed(&mut self, len: usize) -> Result<&'de [u8]> {
+        self.take(len)
+    }
+}
+
+#[cfg(feature = "std")]
+mod io {
+    use std::io::Read;
+
+    use alloc::vec::Vec;
+
+    use super::Reader;
+    use crate::{Error, Result};
+
+    /// Report a stream that ends early as [`Error::Eof`], like the slice
+    /// reader, and any other failure as [`Error::Io`].
+    fn map_io_error(err: std::io::Error) -> Error {
+        if err.kind() == std::io::ErrorKind::UnexpectedEof {
+            Error::Eof
+        } else {
+            err.into()
+        }
+    }
+
+    /// A [`Reader`] that pulls bytes from a [`std::io::Read`], allowing a value
+    /// to be decoded straight off a socket without first buffering the whole
+    /// message.
+    ///
+    /// Reads are copied into an internal scratch buffer, so this reader cannot
+    /// implement [`BorrowReader`](super::BorrowReader) and always hands
+    /// visitors transient (non-borrowed) slices; I/O failures surface as
+    /// [`Error::Io`](crate::Error::Io).
+    pub struct IoReader<R> {
+        reader: R,
+        scratch: Vec<u8>,
+    }
+
+    impl<R: Read> IoReader<R> {
+        /// Create a reader that decodes from `reader`.
+        pub fn new(reader: R) -> Self {
+            Self {
+                reader,
+                scratch: Vec::new(),
+            }
+        }
+    }
+
+    impl<'de, R: Read> Reader<'de> for IoReader<R> {
+        fn read(&mut self, len: usize) -> Result<&[u8]> {
+            self.scratch.resize(len, 0);
+            self.reader
+                .read_exact(&mut self.scratch)
+                .map_err(map_io_error)?;
+            Ok(&self.scratch)
+        }
+
+        fn read_byte(&mut self) -> Result<u8> {
+            let mut byte = [0u8; 1];
+            self.reader.read_exact(&mut byte).map_err(map_io_error)?;
+            Ok(byte[0])
+        }
+
+        fn remaining(&self) -> Option<usize> {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use io::IoReader;
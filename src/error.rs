@@ -12,12 +12,23 @@ pub enum Error {
     #[cfg(feature = "std")]
     Io(String),
     ExceededMaxLen(usize),
+    ExceededByteLimit(usize),
     ExceededContainerDepthLimit(&'static str),
     ExpectedBoolean,
     ExpectedMapKey,
     ExpectedMapValue,
     NonCanonicalMap,
     ExpectedOption,
+    /// A custom error raised by a `Serialize`/`Deserialize` implementation,
+    /// carrying the formatted message and the breadcrumb path of named
+    /// containers and field/element indices leading to the failure (e.g.
+    /// `Service.port[2]`). Falls back to [`SerdeCustom`](Error::SerdeCustom)
+    /// when neither `alloc` nor `std` is available.
+    #[cfg(feature = "alloc")]
+    Custom {
+        path: alloc::string::String,
+        msg: alloc::string::String,
+    },
     SerdeCustom,
     MissingLen,
     NotSupported(&'static str),
@@ -31,6 +42,14 @@ pub enum Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Error::*;
+        #[cfg(feature = "alloc")]
+        if let Custom { path, msg } = self {
+            return if path.is_empty() {
+                write!(f, "{}", msg)
+            } else {
+                write!(f, "failed at {}: {}", path, msg)
+            };
+        }
         write!(
             f,
             "{}",
@@ -39,6 +58,7 @@ impl fmt::Display for Error {
                 #[cfg(feature = "std")]
                 Io(s) => s,
                 ExceededMaxLen(_) => "exceeded max sequence length",
+                ExceededByteLimit(_) => "exceeded max serialized byte limit",
                 ExceededContainerDepthLimit(_) => {
                     "exceeded max container depth while entering"
                 }
@@ -49,6 +69,8 @@ impl fmt::Display for Error {
                     "keys of serialized maps must be unique and in increasing order"
                 }
                 ExpectedOption => "expected option type",
+                #[cfg(feature = "alloc")]
+                Custom { .. } => "", // handled above
                 SerdeCustom => "Serde Custom Error",
                 MissingLen => "sequence missing length",
                 NotSupported(_) => "not supported",
@@ -71,13 +93,85 @@ impl From<std::io::Error> for Error {
     }
 }
 
+/// Drop a leading named-container segment from a breadcrumb path so a child
+/// container reached through a field or element is not re-qualified with its
+/// own type name. `Inner.x` becomes `.x` (the caller prepends the identifying
+/// field/index), while a path already starting with an index like `[2]` is
+/// left untouched.
+#[cfg(feature = "alloc")]
+fn strip_leading_container(path: &str) -> &str {
+    if path.is_empty() || path.starts_with('[') {
+        path
+    } else {
+        let end = path.find(|c| c == '.' || c == '[').unwrap_or(path.len());
+        &path[end..]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Error {
+    /// Prepend an element index like `[2]` to the path of a
+    /// [`Custom`](Error::Custom) error as it unwinds through the serializer.
+    /// A child container's own name is dropped so `Vec<Inner>` failing at `x`
+    /// reads as `items[i].x`, not `items[i].Inner.x`. Other error variants are
+    /// returned unchanged.
+    pub(crate) fn prepend_index(self, index: usize) -> Self {
+        match self {
+            Error::Custom { path, msg } => {
+                let mut joined = alloc::format!("[{}]", index);
+                joined.push_str(strip_leading_container(&path));
+                Error::Custom { path: joined, msg }
+            }
+            other => other,
+        }
+    }
+
+    /// Prepend an already-qualified field segment like `Service.port` to the
+    /// path of a [`Custom`](Error::Custom) error. When the field's value is
+    /// itself a named container, the child container's own name is dropped from
+    /// the front of the existing path — the field name already identifies it —
+    /// so `Outer { inner: Inner { x } }` reads as `Outer.inner.x`, not
+    /// `Outer.inner.Inner.x`. Other error variants are returned unchanged.
+    pub(crate) fn prepend_field(self, segment: &str) -> Self {
+        match self {
+            Error::Custom { path, msg } => {
+                use alloc::string::String;
+                let mut joined = String::from(segment);
+                joined.push_str(strip_leading_container(&path));
+                Error::Custom { path: joined, msg }
+            }
+            other => other,
+        }
+    }
+}
+
 impl ser::Error for Error {
+    #[cfg(feature = "alloc")]
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        use alloc::string::{String, ToString};
+        Error::Custom {
+            path: String::new(),
+            msg: msg.to_string(),
+        }
+    }
+
+    #[cfg(not(feature = "alloc"))]
     fn custom<T: fmt::Display>(_msg: T) -> Self {
         Error::SerdeCustom
     }
 }
 
 impl de::Error for Error {
+    #[cfg(feature = "alloc")]
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        use alloc::string::{String, ToString};
+        Error::Custom {
+            path: String::new(),
+            msg: msg.to_string(),
+        }
+    }
+
+    #[cfg(not(feature = "alloc"))]
     fn custom<T: fmt::Display>(_msg: T) -> Self {
         Error::SerdeCustom
     }
@@ -30,6 +30,226 @@ mod vec {
     }
 }
 
+#[cfg(feature = "std")]
+mod io {
+    use std::io::Write;
+
+    use super::Flavor;
+    use crate::{Error, Result};
+
+    /// A [`Flavor`] that streams each serialized byte slice straight into an
+    /// underlying [`std::io::Write`], so large values can be written to a file
+    /// or socket without first buffering the whole encoding in memory.
+    ///
+    /// Because [`Flavor::extend`] has no error channel, the first write failure
+    /// is latched and surfaced from [`finalize`](Flavor::finalize) as
+    /// [`Error::Io`]; any later bytes are silently dropped until then.
+    pub struct IoWriter<W> {
+        writer: W,
+        error: Option<Error>,
+    }
+
+    impl<W: Write> IoWriter<W> {
+        /// Wrap `writer` so serialized bytes are forwarded to it.
+        pub fn new(writer: W) -> Self {
+            Self {
+                writer,
+                error: None,
+            }
+        }
+    }
+
+    impl<W: Write> Flavor for IoWriter<W> {
+        type Output = Result<W>;
+
+        fn extend(&mut self, data: &[u8]) {
+            if self.error.is_some() {
+                return;
+            }
+            if let Err(err) = self.writer.write_all(data) {
+                self.error = Some(err.into());
+            }
+        }
+
+        fn finalize(mut self) -> Self::Output {
+            if let Some(err) = self.error.take() {
+                return Err(err);
+            }
+            self.writer.flush()?;
+            Ok(self.writer)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use io::IoWriter;
+
+/// A composable [`Flavor`] that caps the total size of the produced encoding.
+///
+/// Wraps an inner flavor `F` and a caller-supplied byte budget. As soon as the
+/// running total would exceed the budget the overflow is latched and no further
+/// bytes reach the inner flavor; [`finalize`](Flavor::finalize) then reports
+/// [`Error::ExceededByteLimit`] with the offending total instead of returning
+/// the inner output. This bounds memory use when serializing untrusted or
+/// quota-limited payloads.
+pub struct Limit<F> {
+    inner: F,
+    limit: usize,
+    written: usize,
+    overflow: Option<usize>,
+}
+
+impl<F> Limit<F> {
+    /// Wrap `inner`, aborting if more than `limit` bytes are written.
+    pub fn new(inner: F, limit: usize) -> Self {
+        Self {
+            inner,
+            limit,
+            written: 0,
+            overflow: None,
+        }
+    }
+}
+
+impl<F> Flavor for Limit<F>
+where
+    F: Flavor,
+{
+    type Output = crate::Result<F::Output>;
+
+    fn extend(&mut self, data: &[u8]) {
+        if self.overflow.is_some() {
+            return;
+        }
+        let total = self.written.saturating_add(data.len());
+        if total > self.limit {
+            self.overflow = Some(total);
+            return;
+        }
+        self.written = total;
+        self.inner.extend(data);
+    }
+
+    fn finalize(self) -> Self::Output {
+        match self.overflow {
+            Some(total) => Err(crate::Error::ExceededByteLimit(total)),
+            None => Ok(self.inner.finalize()),
+        }
+    }
+}
+
+/// A digest that can be fed the serialized bytes incrementally.
+///
+/// Kept deliberately small so callers can plug in an external hash (SHA-256,
+/// Blake2, ...) without this crate taking a dependency on a particular hashing
+/// library. The built-in [`Crc32`] implements it directly.
+pub trait Hasher {
+    /// The value produced once all bytes have been fed in, e.g. `Vec<u8>` or a
+    /// fixed-size array such as `[u8; 32]`.
+    type Output;
+
+    /// Feed the next chunk of bytes into the digest.
+    fn update(&mut self, data: &[u8]);
+
+    /// Consume the digest and return the final hash.
+    fn finalize(self) -> Self::Output;
+}
+
+/// A composable [`Flavor`] that computes a content hash while serializing.
+///
+/// Each [`extend`](Flavor::extend) forwards its bytes both to the inner flavor
+/// `F` and to the digest `H`, so [`serialize_with_flavor`] can produce the BCS
+/// encoding and its digest in a single pass instead of hashing a second time
+/// over a finished buffer. [`finalize`](Flavor::finalize) returns the inner
+/// flavor's output paired with the hash.
+///
+/// [`serialize_with_flavor`]: crate::ser::serialize_with_flavor
+pub struct Hashing<H, F> {
+    hasher: H,
+    inner: F,
+}
+
+impl<H, F> Hashing<H, F> {
+    /// Wrap `inner` so the bytes it receives are also fed into `hasher`.
+    pub fn new(hasher: H, inner: F) -> Self {
+        Self { hasher, inner }
+    }
+}
+
+impl<H, F> Flavor for Hashing<H, F>
+where
+    H: Hasher,
+    F: Flavor,
+{
+    type Output = (F::Output, H::Output);
+
+    fn extend(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+        self.inner.extend(data);
+    }
+
+    fn finalize(self) -> Self::Output {
+        (self.inner.finalize(), self.hasher.finalize())
+    }
+}
+
+/// A CRC-32 digest using the standard reflected polynomial `0xEDB88320`.
+///
+/// Provided so the [`Hashing`] flavor is useful out of the box without pulling
+/// in a hashing crate; for cryptographic object IDs plug a real hash into
+/// [`Hashing`] via the [`Hasher`] trait instead.
+pub struct Crc32 {
+    crc: u32,
+    table: [u32; 256],
+}
+
+impl Crc32 {
+    /// Create a CRC-32 hasher with the conventional initial value.
+    pub fn new() -> Self {
+        let mut table = [0u32; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 != 0 {
+                    (crc >> 1) ^ 0xEDB8_8320
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        Self {
+            crc: 0xFFFF_FFFF,
+            table,
+        }
+    }
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for Crc32 {
+    type Output = u32;
+
+    fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            let index = ((self.crc ^ byte as u32) & 0xFF) as usize;
+            self.crc = (self.crc >> 8) ^ self.table[index];
+        }
+    }
+
+    fn finalize(self) -> u32 {
+        self.crc ^ 0xFFFF_FFFF
+    }
+}
+
 #[derive(Default)]
 pub struct Size(usize);
 
@@ -44,3 +264,68 @@ impl Flavor for Size {
         self.0
     }
 }
+
+#[cfg(all(test, feature = "alloc"))]
+mod digest_tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn crc32_known_vector() {
+        let mut crc = Crc32::new();
+        crc.update(b"123456789");
+        assert_eq!(crc.finalize(), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn hashing_forwards_and_digests_in_one_pass() {
+        let mut flavor = Hashing::new(Crc32::new(), Vec::new());
+        flavor.extend(b"12345");
+        flavor.extend(b"6789");
+        let (bytes, crc) = flavor.finalize();
+        // The inner flavor still sees every byte...
+        assert_eq!(bytes, b"123456789");
+        // ...and the digest matches a standalone pass over the same input.
+        assert_eq!(crc, 0xCBF4_3926);
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod limit_tests {
+    use super::*;
+    use crate::Error;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn limit_fires_exactly_at_the_boundary() {
+        // Writing exactly `limit` bytes succeeds.
+        let mut flavor = Limit::new(Vec::new(), 4);
+        flavor.extend(&[1, 2, 3, 4]);
+        assert_eq!(flavor.finalize().unwrap(), alloc::vec![1, 2, 3, 4]);
+
+        // One byte over the budget fails, reporting the offending total.
+        let mut flavor = Limit::new(Vec::new(), 4);
+        flavor.extend(&[1, 2, 3, 4, 5]);
+        assert!(matches!(flavor.finalize(), Err(Error::ExceededByteLimit(5))));
+    }
+
+    #[test]
+    fn limit_does_not_advance_on_overflowing_extend() {
+        let mut flavor = Limit::new(Vec::new(), 4);
+        flavor.extend(&[1, 2, 3]);
+        assert_eq!(flavor.written, 3);
+        assert!(flavor.overflow.is_none());
+
+        // This extend would bring the total to 5 > 4: it must be rejected
+        // without advancing `written` or touching the inner flavor.
+        flavor.extend(&[4, 5]);
+        assert_eq!(flavor.written, 3);
+        assert_eq!(flavor.overflow, Some(5));
+        assert_eq!(flavor.inner, alloc::vec![1, 2, 3]);
+
+        // Once latched, later (individually fitting) writes stay dropped.
+        flavor.extend(&[6]);
+        assert_eq!(flavor.inner, alloc::vec![1, 2, 3]);
+        assert!(matches!(flavor.finalize(), Err(Error::ExceededByteLimit(5))));
+    }
+}
@@ -35,6 +35,61 @@ where
     Ok(storage.finalize())
 }
 
+/// Serialize the given data structure directly into an [`io::Write`], streaming
+/// each byte slice as it is produced rather than buffering the whole encoding in
+/// a `Vec<u8>` first.
+///
+/// This routes through [`serialize_with_flavor`] with the
+/// [`flavors::IoWriter`](crate::ser::flavors::IoWriter) flavor, so any write
+/// failure is reported as [`Error::Io`].
+///
+/// Note that serialization does not abort early on a write error: the first
+/// failure is latched and every subsequent byte is silently dropped while the
+/// serializer finishes walking the value, so the error only surfaces when this
+/// function returns. Callers needing an early abort should buffer with
+/// [`to_bytes`] instead.
+///
+/// [`io::Write`]: std::io::Write
+#[cfg(feature = "std")]
+pub fn serialize_into<W, T>(writer: W, value: &T) -> Result<()>
+where
+    W: std::io::Write,
+    T: ?Sized + Serialize,
+{
+    serialize_with_flavor(value, flavors::IoWriter::new(writer))??;
+    Ok(())
+}
+
+/// Same as [`serialize_with_flavor`] but fails with [`Error::ExceededByteLimit`]
+/// if the running total of produced bytes would exceed `size_limit`, wrapping
+/// `storage` in the [`flavors::Limit`] flavor.
+///
+/// Once the budget is exceeded no further bytes reach `storage`, but the
+/// serializer still walks the rest of the value; the error is returned when
+/// serialization completes.
+pub fn serialize_with_flavor_and_size_limit<T, S, O>(
+    value: &T,
+    storage: S,
+    size_limit: usize,
+) -> Result<O>
+where
+    T: Serialize + ?Sized,
+    S: Flavor<Output = O>,
+{
+    serialize_with_flavor(value, flavors::Limit::new(storage, size_limit))?
+}
+
+/// Same as [`to_bytes`] but aborts with [`Error::ExceededByteLimit`] if the
+/// serialized form would be larger than `size_limit` bytes.
+#[cfg(feature = "alloc")]
+pub fn to_bytes_with_size_limit<T>(value: &T, size_limit: usize) -> Result<Vec<u8>>
+where
+    T: ?Sized + Serialize,
+{
+    let output = Vec::new();
+    serialize_with_flavor_and_size_limit(value, output, size_limit)
+}
+
 pub fn is_human_readable() -> bool {
     let mut output = Vec::new();
     let serializer = Serializer::new(&mut output, crate::MAX_CONTAINER_DEPTH);
@@ -115,3 +170,43 @@ where
     let output = Vec::new();
     serialize_with_flavor_and_limit(value, output, limit)
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{serialize_into, to_bytes};
+    use crate::Error;
+
+    // A writer that accepts `allow` bytes and then fails every further write.
+    struct FailingWriter {
+        allow: usize,
+    }
+
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if self.allow == 0 {
+                return Err(std::io::Error::new(std::io::ErrorKind::Other, "nope"));
+            }
+            let n = buf.len().min(self.allow);
+            self.allow -= n;
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn serialize_into_matches_to_bytes() {
+        let value: (u32, bool, &str) = (0x1234_5678, true, "hi");
+        let mut buf = Vec::new();
+        serialize_into(&mut buf, &value).unwrap();
+        assert_eq!(buf, to_bytes(&value).unwrap());
+    }
+
+    #[test]
+    fn serialize_into_latches_writer_error() {
+        let err = serialize_into(FailingWriter { allow: 0 }, &0x1234_5678u32).unwrap_err();
+        assert!(matches!(err, Error::Io(_)));
+    }
+}
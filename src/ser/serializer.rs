@@ -7,6 +7,13 @@ use serde::{ser, Serialize};
 pub struct Serializer<'a, F> {
     output: &'a mut F,
     max_remaining_depth: usize,
+    /// Name of the named container currently being serialized, used to build
+    /// the breadcrumb path attached to custom errors.
+    #[cfg(feature = "alloc")]
+    container_name: Option<&'static str>,
+    /// Running element index within a sequence, used for the breadcrumb path.
+    #[cfg(feature = "alloc")]
+    index: usize,
 }
 
 impl<'a, F> Serializer<'a, F>
@@ -18,6 +25,10 @@ where
         Self {
             output,
             max_remaining_depth,
+            #[cfg(feature = "alloc")]
+            container_name: None,
+            #[cfg(feature = "alloc")]
+            index: 0,
         }
     }
 
@@ -45,6 +56,16 @@ where
         self.output_u32_as_uleb128(len as u32)
     }
 
+    /// Render the breadcrumb segment for `field`, qualifying it with the
+    /// current container name when one is known (e.g. `Service.port`).
+    #[cfg(feature = "alloc")]
+    fn field_segment(&self, field: &'static str) -> alloc::string::String {
+        match self.container_name {
+            Some(name) => alloc::format!("{}.{}", name, field),
+            None => alloc::string::String::from(field),
+        }
+    }
+
     fn enter_named_container(&mut self, name: &'static str) -> Result<()> {
         if self.max_remaining_depth == 0 {
             return Err(Error::ExceededContainerDepthLimit(name));
@@ -256,6 +277,10 @@ where
         _len: usize,
     ) -> Result<Self::SerializeStruct> {
         self.enter_named_container(name)?;
+        #[cfg(feature = "alloc")]
+        {
+            self.container_name = Some(name);
+        }
         Ok(self)
     }
 
@@ -268,6 +293,10 @@ where
     ) -> Result<Self::SerializeStructVariant> {
         self.enter_named_container(name)?;
         self.output_variant_index(variant_index)?;
+        #[cfg(feature = "alloc")]
+        {
+            self.container_name = Some(name);
+        }
         Ok(self)
     }
 
@@ -288,7 +317,14 @@ where
     where
         T: ?Sized + Serialize,
     {
-        value.serialize(Serializer::new(self.output, self.max_remaining_depth))
+        let result = value.serialize(Serializer::new(self.output, self.max_remaining_depth));
+        #[cfg(feature = "alloc")]
+        let result = {
+            let index = self.index;
+            self.index += 1;
+            result.map_err(|e| e.prepend_index(index))
+        };
+        result
     }
 
     fn end(self) -> Result<()> {
@@ -397,7 +433,8 @@ mod map_ser {
             key.serialize(Serializer::new(
                 &mut output,
                 self.serializer.max_remaining_depth,
-            ))?;
+            ))
+            .map_err(|e| e.prepend_index(self.entries.len()))?;
             self.next_key = Some(output);
             Ok(())
         }
@@ -412,7 +449,8 @@ mod map_ser {
                     value.serialize(Serializer::new(
                         &mut output,
                         self.serializer.max_remaining_depth,
-                    ))?;
+                    ))
+                    .map_err(|e| e.prepend_index(self.entries.len()))?;
                     self.entries.push((key, output));
                     Ok(())
                 }
@@ -477,11 +515,14 @@ where
     type Ok = ();
     type Error = Error;
 
-    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<()>
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
     where
         T: ?Sized + Serialize,
     {
-        value.serialize(Serializer::new(self.output, self.max_remaining_depth))
+        let result = value.serialize(Serializer::new(self.output, self.max_remaining_depth));
+        #[cfg(feature = "alloc")]
+        let result = result.map_err(|e| e.prepend_field(&self.field_segment(key)));
+        result
     }
 
     fn end(self) -> Result<()> {
@@ -496,14 +537,122 @@ where
     type Ok = ();
     type Error = Error;
 
-    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<()>
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
     where
         T: ?Sized + Serialize,
     {
-        value.serialize(Serializer::new(self.output, self.max_remaining_depth))
+        let result = value.serialize(Serializer::new(self.output, self.max_remaining_depth));
+        #[cfg(feature = "alloc")]
+        let result = result.map_err(|e| e.prepend_field(&self.field_segment(key)));
+        result
     }
 
     fn end(self) -> Result<()> {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::ser::to_bytes;
+    use crate::Error;
+    use serde::ser::{SerializeStruct, Serialize, Serializer};
+
+    // An element that serializes as a `u16`, or fails with a custom error when
+    // asked to, so we can exercise the breadcrumb path.
+    struct Elem(bool);
+
+    impl Serialize for Elem {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if self.0 {
+                Err(serde::ser::Error::custom("boom"))
+            } else {
+                serializer.serialize_u16(0)
+            }
+        }
+    }
+
+    struct Service {
+        port: alloc::vec::Vec<Elem>,
+    }
+
+    impl Serialize for Service {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("Service", 1)?;
+            s.serialize_field("port", &self.port)?;
+            s.end()
+        }
+    }
+
+    struct Inner {
+        x: Elem,
+    }
+
+    impl Serialize for Inner {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("Inner", 1)?;
+            s.serialize_field("x", &self.x)?;
+            s.end()
+        }
+    }
+
+    struct Outer {
+        inner: Inner,
+    }
+
+    impl Serialize for Outer {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("Outer", 1)?;
+            s.serialize_field("inner", &self.inner)?;
+            s.end()
+        }
+    }
+
+    #[test]
+    fn custom_error_records_field_and_index_path() {
+        let service = Service {
+            port: alloc::vec![Elem(false), Elem(false), Elem(true)],
+        };
+        match to_bytes(&service) {
+            Err(Error::Custom { path, msg }) => {
+                assert_eq!(path, "Service.port[2]");
+                assert_eq!(msg, "boom");
+            }
+            other => panic!("expected custom error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn nested_named_container_is_not_re_qualified() {
+        let outer = Outer {
+            inner: Inner { x: Elem(true) },
+        };
+        match to_bytes(&outer) {
+            Err(Error::Custom { path, .. }) => assert_eq!(path, "Outer.inner.x"),
+            other => panic!("expected custom error, got {:?}", other),
+        }
+    }
+
+    struct Collection {
+        items: alloc::vec::Vec<Inner>,
+    }
+
+    impl Serialize for Collection {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("Collection", 1)?;
+            s.serialize_field("items", &self.items)?;
+            s.end()
+        }
+    }
+
+    #[test]
+    fn container_in_sequence_element_is_not_re_qualified() {
+        let collection = Collection {
+            items: alloc::vec![Inner { x: Elem(false) }, Inner { x: Elem(true) }],
+        };
+        match to_bytes(&collection) {
+            Err(Error::Custom { path, .. }) => assert_eq!(path, "Collection.items[1].x"),
+            other => panic!("expected custom error, got {:?}", other),
+        }
+    }
+}